@@ -1,13 +1,19 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::str::FromStr;
+
 use anyhow::{Context, Result};
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use mpl_token_metadata::accounts::Metadata;
 use mpl_token_metadata::ID as METAPLEX_PROGRAM_ID;
 use serde::{Deserialize, Serialize};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_response::RpcTokenAccountBalance;
 use spl_token::solana_program::program_option::COption;
 use spl_token::solana_program::program_pack::Pack;
 use spl_token::solana_program::pubkey::Pubkey;
-use spl_token::state::Mint;
+use spl_token::state::{Account as TokenAccount, Mint};
 use trust_dns_resolver::config::{ResolverConfig, ResolverOpts};
 use trust_dns_resolver::TokioAsyncResolver;
 use url::Url;
@@ -15,28 +21,92 @@ use url::Url;
 const METADATA_SEED: &[u8; 8] = b"metadata";
 pub const UNAVAILABLE: &str = "Not available";
 
+// SPL token incinerator: the conventional burn-account owner, excluded from
+// holder concentration so burned supply isn't counted as a "holder".
+const TOKEN_INCINERATOR: &str = "1nc1nerator11111111111111111111111111111111";
+
 #[derive(Parser)]
 #[command(about = "Fetch on/off chain token details", long_about = None)]
 pub struct ProphetsCli {
-    /// Solana mint account address
-    pub token_address: Pubkey,
+    #[command(subcommand)]
+    pub command: ProphetsCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ProphetsCommand {
+    /// Fetch details for a single token mint
+    Inspect {
+        /// Solana mint account address
+        token_address: Pubkey,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Fetch details for many token mints concurrently
+    Batch {
+        /// File of newline-delimited mint addresses; reads stdin when omitted
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Maximum number of mints to resolve concurrently
+        #[arg(long, default_value_t = 10)]
+        concurrency: usize,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Debug, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Reads newline-delimited mint addresses from `reader`, ignoring blank lines.
+pub fn read_mint_addresses<R: BufRead>(reader: R) -> Result<Vec<String>> {
+    reader
+        .lines()
+        .map(|line| line.with_context(|| "Failed to read mint address list"))
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| line.map(|line| line.trim().to_string()))
+        .collect()
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
     rpc_url: String,
+    das_url: String,
+    token_bridge_program_id: String,
+    wormhole_core_program_id: String,
 }
 
 impl Config {
     pub fn rpc_url(&self) -> &str {
         &self.rpc_url
     }
+
+    pub fn das_url(&self) -> &str {
+        &self.das_url
+    }
+
+    pub fn token_bridge_program_id(&self) -> &str {
+        &self.token_bridge_program_id
+    }
+
+    pub fn wormhole_core_program_id(&self) -> &str {
+        &self.wormhole_core_program_id
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
+        let rpc_url = "https://api.mainnet-beta.solana.com".to_string();
+
         Config {
-            rpc_url: "https://api.mainnet-beta.solana.com".to_string(),
+            das_url: rpc_url.clone(),
+            rpc_url,
+            token_bridge_program_id: "wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb".to_string(),
+            wormhole_core_program_id: "worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth".to_string(),
         }
     }
 }
@@ -138,11 +208,42 @@ pub async fn fetch_token_mintdata(client: &RpcClient, mint_pubkey: &Pubkey) -> R
     Ok(mint_info)
 }
 
+#[derive(Serialize, Debug)]
+pub struct CreatorInfo {
+    pub address: String,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CollectionInfo {
+    pub mint: String,
+    pub verified: bool,
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub offchain: OffChainMetadata,
+    pub dns_records: Option<String>,
+    pub creators: Vec<CreatorInfo>,
+    pub collection: Option<CollectionInfo>,
+    pub is_compressed: bool,
+}
+
 pub async fn fetch_token_metadata(
     client: &RpcClient,
     mint_pubkey: &Pubkey,
-) -> Result<(String, String, OffChainMetadata, Option<String>)> {
-    let metadata = fetch_on_chain_metadata(client, mint_pubkey).await?;
+    config: &Config,
+) -> Result<TokenMetadata> {
+    // Compressed (Bubblegum) NFTs have no per-mint Metaplex Metadata account,
+    // so fall back to the DAS getAsset read API when the on-chain PDA is missing.
+    let metadata = match fetch_on_chain_metadata(client, mint_pubkey).await {
+        Ok(metadata) => metadata,
+        Err(_) => return fetch_compressed_token_metadata(config.das_url(), mint_pubkey).await,
+    };
 
     // Off-chain metadata is depedent of on-chain metadata (uri), thus this should happen sequentially
     let offchain_metadata = fetch_off_chain_metadata(&metadata.uri)
@@ -151,19 +252,319 @@ pub async fn fetch_token_metadata(
 
     let dns_records = fetch_dns_records(&offchain_metadata.website).await;
 
-    Ok((
-        metadata.name.trim_end_matches(char::from(0)).to_string(),
-        metadata.symbol.trim_end_matches(char::from(0)).to_string(),
-        offchain_metadata,
+    let creators = metadata
+        .creators
+        .unwrap_or_default()
+        .into_iter()
+        .map(|creator| CreatorInfo {
+            address: creator.address.to_string(),
+            verified: creator.verified,
+            share: creator.share,
+        })
+        .collect();
+
+    let collection = match metadata.collection {
+        Some(collection) => Some(CollectionInfo {
+            mint: collection.key.to_string(),
+            verified: collection.verified,
+            name: fetch_collection_name(client, &collection).await,
+        }),
+        None => None,
+    };
+
+    Ok(TokenMetadata {
+        name: metadata.name.trim_end_matches(char::from(0)).to_string(),
+        symbol: metadata.symbol.trim_end_matches(char::from(0)).to_string(),
+        offchain: offchain_metadata,
         dns_records,
-    ))
+        creators,
+        collection,
+        is_compressed: false,
+    })
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct DasAssetContentMetadata {
+    name: Option<String>,
+    symbol: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct DasAssetContentLinks {
+    image: Option<String>,
+    external_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct DasAssetContent {
+    metadata: DasAssetContentMetadata,
+    links: Option<DasAssetContentLinks>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DasAsset {
+    content: DasAssetContent,
+}
+
+#[derive(Deserialize, Debug)]
+struct DasGetAssetResponse {
+    result: Option<DasAsset>,
+}
+
+// Function to fetch a compressed NFT's metadata via the DAS getAsset read API
+async fn fetch_compressed_token_metadata(
+    das_url: &str,
+    mint_pubkey: &Pubkey,
+) -> Result<TokenMetadata> {
+    let request_body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "prophetbots-cli",
+        "method": "getAsset",
+        "params": { "id": mint_pubkey.to_string() },
+    });
+
+    let response: DasGetAssetResponse = reqwest::Client::new()
+        .post(das_url)
+        .json(&request_body)
+        .send()
+        .await
+        .with_context(|| "Failed to call DAS getAsset")?
+        .json()
+        .await
+        .with_context(|| "Failed to parse DAS getAsset response")?;
+
+    let asset = response
+        .result
+        .with_context(|| "Failed to load on-chain or DAS metadata, the address may be invalid")?;
+
+    let links = asset.content.links.unwrap_or_default();
+    let offchain_metadata = OffChainMetadata {
+        description: asset.content.metadata.description,
+        image: links.image,
+        website: links.external_url,
+    };
+
+    let dns_records = fetch_dns_records(&offchain_metadata.website).await;
+
+    Ok(TokenMetadata {
+        name: asset.content.metadata.name.unwrap_or_default(),
+        symbol: asset.content.metadata.symbol.unwrap_or_default(),
+        offchain: offchain_metadata,
+        dns_records,
+        creators: Vec::new(),
+        collection: None,
+        is_compressed: true,
+    })
+}
+
+// Resolves the parent collection's name for a verified collection member, so
+// a copycat that merely copies a name/symbol can be told apart from the real
+// collection mint.
+async fn fetch_collection_name(
+    client: &RpcClient,
+    collection: &mpl_token_metadata::types::Collection,
+) -> Option<String> {
+    if !collection.verified {
+        return None;
+    }
+
+    let collection_mint = Pubkey::new_from_array(collection.key.to_bytes());
+    let collection_metadata = fetch_on_chain_metadata(client, &collection_mint)
+        .await
+        .ok()?;
+
+    Some(
+        collection_metadata
+            .name
+            .trim_end_matches(char::from(0))
+            .to_string(),
+    )
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct HolderDistribution {
+    pub top1_pct: f64,
+    pub top10_pct: f64,
+    pub hhi: f64,
+}
+
+// Function to fetch concentration metrics across the largest token accounts for a mint
+pub async fn fetch_holder_distribution(
+    client: &RpcClient,
+    mint_pubkey: &Pubkey,
+    mint: &Mint,
+) -> Result<HolderDistribution> {
+    if mint.supply == 0 {
+        return Ok(HolderDistribution::default());
+    }
+
+    let largest_accounts = client
+        .get_token_largest_accounts(mint_pubkey)
+        .await
+        .with_context(|| "Failed to load largest token accounts")?;
+
+    // Keep only the largest accounts whose address actually parses, paired
+    // with their pubkey, so the accounts fetched below line up one-to-one
+    // with the entry they belong to.
+    let parsed_accounts: Vec<(Pubkey, &RpcTokenAccountBalance)> = largest_accounts
+        .iter()
+        .filter_map(|account| Some((Pubkey::from_str(&account.address).ok()?, account)))
+        .collect();
+
+    let account_pubkeys: Vec<Pubkey> = parsed_accounts
+        .iter()
+        .map(|(pubkey, _)| *pubkey)
+        .collect();
+
+    // Largest-account balances belong to token accounts, not owner wallets, so
+    // resolve each account's owner and sum shares held by the same owner
+    // across multiple accounts before computing concentration.
+    let accounts = client
+        .get_multiple_accounts(&account_pubkeys)
+        .await
+        .with_context(|| "Failed to load token accounts for holder grouping")?;
+
+    let incinerator =
+        Pubkey::from_str(TOKEN_INCINERATOR).expect("incinerator address is a valid pubkey");
+
+    // `decimals` is an unchecked u8 on the Mint account, so an unusual or
+    // crafted mint could set it high enough to overflow a u64 power of ten.
+    let decimals_scale = 10u64
+        .checked_pow(mint.decimals as u32)
+        .with_context(|| format!("Mint decimals {} are out of range", mint.decimals))?;
+    let ui_supply = mint.supply as f64 / decimals_scale as f64;
+
+    let mut shares_by_owner: HashMap<Pubkey, f64> = HashMap::new();
+    for ((_, largest_account), account) in parsed_accounts.iter().zip(accounts.iter()) {
+        let owner = account
+            .as_ref()
+            .and_then(|account| TokenAccount::unpack(&account.data).ok())
+            .map(|token_account| token_account.owner);
+
+        let Some(owner) = owner else { continue };
+        if owner == incinerator {
+            continue;
+        }
+
+        let ui_amount = largest_account.amount.ui_amount.unwrap_or(0.0);
+        *shares_by_owner.entry(owner).or_insert(0.0) += ui_amount / ui_supply;
+    }
+
+    let mut shares: Vec<f64> = shares_by_owner.into_values().collect();
+    shares.sort_by(|a, b| b.total_cmp(a));
+
+    let top1_pct = shares.first().copied().unwrap_or(0.0) * 100.0;
+    let top10_pct = shares.iter().take(10).sum::<f64>() * 100.0;
+    let hhi = shares.iter().map(|share| share * share).sum();
+
+    Ok(HolderDistribution {
+        top1_pct,
+        top10_pct,
+        hhi,
+    })
+}
+
+const WRAPPED_META_SEED: &[u8] = b"meta";
+
+// Wormhole Token Bridge "wrapped meta" account layout: origin chain id
+// (u16 LE), origin contract address (32 bytes), original decimals (u8).
+struct WrappedMeta {
+    origin_chain: u16,
+    origin_address: [u8; 32],
+}
+
+impl WrappedMeta {
+    fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() < 34 {
+            anyhow::bail!("Wrapped meta account data is too short");
+        }
+
+        let origin_chain = u16::from_le_bytes([data[0], data[1]]);
+        let mut origin_address = [0u8; 32];
+        origin_address.copy_from_slice(&data[2..34]);
+
+        Ok(WrappedMeta {
+            origin_chain,
+            origin_address,
+        })
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct WrappedAssetInfo {
+    pub origin_chain: u16,
+    pub origin_address: String,
+}
+
+// Distinguishes "confirmed wrapped", "confirmed native/unwrapped", and
+// "couldn't tell because the check itself failed" so a scam/rug-signal
+// consumer never mistakes a failed RPC check for a clean native asset.
+#[derive(Serialize, Debug)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum WrappedAssetStatus {
+    Wrapped(WrappedAssetInfo),
+    Native,
+    Unavailable,
 }
 
-pub fn pubkey_to_string(pubkey: COption<Pubkey>) -> String {
+// Function to detect whether a mint is a Wormhole-wrapped representation of
+// an asset from another chain, by checking for its token bridge wrapped meta PDA.
+pub async fn detect_wrapped_asset(
+    client: &RpcClient,
+    mint_pubkey: &Pubkey,
+    config: &Config,
+) -> Result<Option<WrappedAssetInfo>> {
+    let token_bridge_program_id = Pubkey::from_str(config.token_bridge_program_id())
+        .with_context(|| "Invalid token bridge program id in config")?;
+
+    let wrapped_meta_seeds = &[WRAPPED_META_SEED, mint_pubkey.as_ref()];
+    let (wrapped_meta_pubkey, _) =
+        Pubkey::find_program_address(wrapped_meta_seeds, &token_bridge_program_id);
+
+    let account_data = match client.get_account_data(&wrapped_meta_pubkey).await {
+        Ok(data) => data,
+        // Missing PDA means the mint is native/unwrapped, not an error.
+        Err(_) => return Ok(None),
+    };
+
+    let wrapped_meta = WrappedMeta::from_bytes(&account_data)?;
+    let origin_address = wrapped_meta
+        .origin_address
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    Ok(Some(WrappedAssetInfo {
+        origin_chain: wrapped_meta.origin_chain,
+        origin_address,
+    }))
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenReport {
+    pub name: String,
+    pub symbol: String,
+    pub supply: Option<u64>,
+    pub decimals: Option<u8>,
+    pub mint_authority: Option<String>,
+    pub freeze_authority: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub website: Option<String>,
+    pub dns_record_count: Option<String>,
+    pub is_compressed: bool,
+    pub creators: Vec<CreatorInfo>,
+    pub collection: Option<CollectionInfo>,
+    pub holder_distribution: Option<HolderDistribution>,
+    pub wrapped_asset: WrappedAssetStatus,
+}
+
+pub fn pubkey_to_option_string(pubkey: COption<Pubkey>) -> Option<String> {
     if let COption::Some(pubkey) = pubkey {
-        pubkey.to_string()
+        Some(pubkey.to_string())
     } else {
-        UNAVAILABLE.to_string()
+        None
     }
 }
 
@@ -198,13 +599,13 @@ mod cli_tests {
     }
 
     #[test]
-    fn pubkey_to_string_test() {
-        let result = pubkey_to_string(COption::None);
-        assert_eq!(result, UNAVAILABLE.to_string());
+    fn pubkey_to_option_string_test() {
+        let result = pubkey_to_option_string(COption::None);
+        assert_eq!(result, None);
 
         let test_pubkey = Pubkey::new_unique();
-        let result = pubkey_to_string(COption::Some(test_pubkey));
-        assert_eq!(result, test_pubkey.to_string());
+        let result = pubkey_to_option_string(COption::Some(test_pubkey));
+        assert_eq!(result, Some(test_pubkey.to_string()));
     }
 
     #[tokio::test]
@@ -246,27 +647,28 @@ mod cli_tests {
     #[tokio::test]
     async fn fetch_token_metadata_test() {
         let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+        let config = Config::default();
 
         // USDC mint account
         let test_mint_pubkey =
             Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
 
-        let result = fetch_token_metadata(&client, &test_mint_pubkey).await;
+        let result = fetch_token_metadata(&client, &test_mint_pubkey, &config).await;
 
         assert!(result.is_ok());
 
-        let (name, symbol, offchain_metadata, dns_records) = result.unwrap();
-        assert_eq!(name, "USD Coin");
-        assert_eq!(symbol, "USDC");
-        assert_eq!(offchain_metadata.description, None);
-        assert_eq!(offchain_metadata.image, None);
-        assert_eq!(offchain_metadata.website, None);
-        assert_eq!(dns_records, None);
+        let metadata = result.unwrap();
+        assert_eq!(metadata.name, "USD Coin");
+        assert_eq!(metadata.symbol, "USDC");
+        assert_eq!(metadata.offchain.description, None);
+        assert_eq!(metadata.offchain.image, None);
+        assert_eq!(metadata.offchain.website, None);
+        assert_eq!(metadata.dns_records, None);
 
         // non-mint account
         let test_mint_pubkey =
             Pubkey::from_str("BJE5MMbqXjVwjAF7oxwPYXnTXDyspzZyt4vwenNw5ruG").unwrap();
-        let result = fetch_token_metadata(&client, &test_mint_pubkey).await;
+        let result = fetch_token_metadata(&client, &test_mint_pubkey, &config).await;
 
         assert!(result.is_err());
 
@@ -274,27 +676,75 @@ mod cli_tests {
         let test_mint_pubkey =
             Pubkey::from_str("7fxxyaTv3Y19Coc1kwwaniDSHNboNqHTYvVvtMxr1uWo").unwrap();
 
-        let result: std::result::Result<
-            (String, String, OffChainMetadata, Option<String>),
-            anyhow::Error,
-        > = fetch_token_metadata(&client, &test_mint_pubkey).await;
+        let result = fetch_token_metadata(&client, &test_mint_pubkey, &config).await;
 
         assert!(result.is_ok());
-        let (name, symbol, offchain_metadata, dns_records) = result.unwrap();
-        assert_eq!(name, "Signal Boost #088");
-        assert_eq!(symbol, "SGBT2");
-        assert_eq!(offchain_metadata.description, Some("Signal Boost is a 3D art collection by Jack Dupp. It is an exploration of color and light through a process of 3D extrapolation of a 2D artwork. The original artwork is permanently destroyed revealing a new energetic outcome.".to_string()));
+        let metadata = result.unwrap();
+        assert_eq!(metadata.name, "Signal Boost #088");
+        assert_eq!(metadata.symbol, "SGBT2");
+        assert_eq!(metadata.offchain.description, Some("Signal Boost is a 3D art collection by Jack Dupp. It is an exploration of color and light through a process of 3D extrapolation of a 2D artwork. The original artwork is permanently destroyed revealing a new energetic outcome.".to_string()));
         assert_eq!(
-            offchain_metadata.website,
+            metadata.offchain.website,
             Some("https://abstractlabs.art".to_string())
         );
         assert_eq!(
-            offchain_metadata.image,
+            metadata.offchain.image,
             Some(
                 "https://www.arweave.net/eY9gWuLKyBRsNv30Xug79GzjfiW4DJ2xfoFMa1-RZ8A?ext=jpg"
                     .to_string()
             )
         );
-        assert_eq!(dns_records, None);
+        assert_eq!(metadata.dns_records, None);
+        assert!(!metadata.creators.is_empty());
+        assert!(metadata.creators.iter().all(|creator| creator.share <= 100));
+    }
+
+    #[tokio::test]
+    async fn fetch_holder_distribution_test() {
+        let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+
+        // USDC mint account
+        let test_mint_pubkey =
+            Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        let mint = fetch_token_mintdata(&client, &test_mint_pubkey)
+            .await
+            .unwrap();
+
+        let result = fetch_holder_distribution(&client, &test_mint_pubkey, &mint).await;
+
+        assert!(result.is_ok());
+        let holders = result.unwrap();
+        assert!(holders.top1_pct > 0.0);
+        assert!(holders.top10_pct >= holders.top1_pct);
+        assert!(holders.hhi > 0.0);
+    }
+
+    #[tokio::test]
+    async fn detect_wrapped_asset_test() {
+        let client = RpcClient::new("https://api.mainnet-beta.solana.com".to_string());
+        let config = Config::default();
+
+        // USDC mint account: native to Solana, not a Wormhole-wrapped asset.
+        let test_mint_pubkey =
+            Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+
+        let result = detect_wrapped_asset(&client, &test_mint_pubkey, &config).await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn read_mint_addresses_test() {
+        let input = b"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v\n\n9KgvborfMPc1nzhXe9N8Q9pKTt57YdBWt9VqHnibdqjC\n  \n";
+        let result = read_mint_addresses(&input[..]).unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+                "9KgvborfMPc1nzhXe9N8Q9pKTt57YdBWt9VqHnibdqjC".to_string(),
+            ]
+        );
     }
 }
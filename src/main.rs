@@ -1,18 +1,22 @@
 use std::{
-    io::{self, Write},
+    fs::File,
+    io::{self, BufReader, Write},
+    path::PathBuf,
+    str::FromStr,
     time::Duration,
 };
 
 use anyhow::{Context, Result};
 use clap::Parser;
+use futures::stream::{self, StreamExt};
 use indicatif::ProgressBar;
 use prophetbots_cli::*;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use spl_token::solana_program::pubkey::Pubkey;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = ProphetsCli::parse();
-    let token_address = cli.token_address;
 
     // Load config
     let cfg = get_config().with_context(|| "Unable to load CLI config")?;
@@ -21,74 +25,320 @@ async fn main() -> Result<()> {
     // Create Solana RPC client
     let client = RpcClient::new(rpc_url.to_string());
 
+    match cli.command {
+        ProphetsCommand::Inspect {
+            token_address,
+            format,
+        } => inspect(&client, &token_address, &cfg, format).await,
+        ProphetsCommand::Batch { file, concurrency } => {
+            batch(&client, file, concurrency, &cfg).await
+        }
+    }
+}
+
+async fn inspect(
+    client: &RpcClient,
+    token_address: &Pubkey,
+    config: &Config,
+    format: OutputFormat,
+) -> Result<()> {
     // Set up a spinner
     let bar = ProgressBar::new_spinner();
     bar.enable_steady_tick(Duration::from_millis(100));
     bar.set_message("Fetching token details...");
 
     // Fetch token information concurrently
-    let (metadata_rest, mintdata_res) = tokio::join!(
-        fetch_token_metadata(&client, &token_address),
-        fetch_token_mintdata(&client, &token_address),
+    let (metadata_res, mintdata_res) = tokio::join!(
+        fetch_token_metadata(client, token_address, config),
+        fetch_token_mintdata(client, token_address),
     );
 
     bar.finish();
 
-    let (token_name, token_symbol, offchain_data, dns_records) =
-        metadata_rest.with_context(|| {
-            "Failed to retrieve token metadata, it's likely because the token address is invalid"
-        })?;
-
-    let mintdata = mintdata_res.with_context(|| {
-        "Failed to retrieve token mint data, it's likely because the token address is invalid"
+    let metadata = metadata_res.with_context(|| {
+        "Failed to retrieve token metadata, it's likely because the token address is invalid"
     })?;
 
+    // Compressed (Bubblegum) NFTs have no per-mint SPL Mint account at the
+    // asset id, so a missing mint account is expected there rather than a
+    // sign of an invalid address.
+    let mintdata = match mintdata_res {
+        Ok(mintdata) => Some(mintdata),
+        Err(_) if metadata.is_compressed => None,
+        Err(err) => {
+            return Err(err).with_context(|| {
+                "Failed to retrieve token mint data, it's likely because the token address is invalid"
+            })
+        }
+    };
+
+    let holder_distribution = match &mintdata {
+        Some(mintdata) => fetch_holder_distribution(client, token_address, mintdata)
+            .await
+            .ok(),
+        None => None,
+    };
+    // Keep "check failed" distinct from "confirmed native" so a flaky RPC
+    // call can never be reported as a clean native asset.
+    let wrapped_asset = match detect_wrapped_asset(client, token_address, config).await {
+        Ok(Some(info)) => WrappedAssetStatus::Wrapped(info),
+        Ok(None) => WrappedAssetStatus::Native,
+        Err(_) => WrappedAssetStatus::Unavailable,
+    };
+
+    let report = TokenReport {
+        name: metadata.name,
+        symbol: metadata.symbol,
+        supply: mintdata.as_ref().map(|mintdata| mintdata.supply),
+        decimals: mintdata.as_ref().map(|mintdata| mintdata.decimals),
+        mint_authority: mintdata
+            .as_ref()
+            .and_then(|mintdata| pubkey_to_option_string(mintdata.mint_authority)),
+        freeze_authority: mintdata
+            .as_ref()
+            .and_then(|mintdata| pubkey_to_option_string(mintdata.freeze_authority)),
+        description: metadata.offchain.description,
+        image: metadata.offchain.image,
+        website: metadata.offchain.website,
+        dns_record_count: metadata.dns_records,
+        is_compressed: metadata.is_compressed,
+        creators: metadata.creators,
+        collection: metadata.collection,
+        holder_distribution,
+        wrapped_asset,
+    };
+
+    match format {
+        OutputFormat::Text => print_text_report(&report),
+        OutputFormat::Json => print_json_report(&report),
+    }
+}
+
+fn print_text_report(report: &TokenReport) -> Result<()> {
     let stdout = io::stdout();
     let mut handle = io::BufWriter::new(stdout);
 
-    // Output token information
-    writeln!(handle, "Token Name: {}", token_name)?;
-    writeln!(handle, "Token Symbol: {}", token_symbol)?;
-    writeln!(handle, "Total Supply: {}", mintdata.supply)?;
-    writeln!(handle, "Decimals: {}", mintdata.decimals)?;
+    writeln!(handle, "Token Name: {}", report.name)?;
+    writeln!(handle, "Token Symbol: {}", report.symbol)?;
+    writeln!(
+        handle,
+        "Total Supply: {}",
+        report
+            .supply
+            .map(|supply| supply.to_string())
+            .unwrap_or_else(|| UNAVAILABLE.to_string())
+    )?;
+    writeln!(
+        handle,
+        "Decimals: {}",
+        report
+            .decimals
+            .map(|decimals| decimals.to_string())
+            .unwrap_or_else(|| UNAVAILABLE.to_string())
+    )?;
     writeln!(
         handle,
         "Mint Authority: {}",
-        pubkey_to_string(mintdata.mint_authority)
+        report.mint_authority.as_deref().unwrap_or(UNAVAILABLE)
     )?;
 
     writeln!(
         handle,
         "Freeze Authority: {}",
-        pubkey_to_string(mintdata.freeze_authority)
+        report.freeze_authority.as_deref().unwrap_or(UNAVAILABLE)
     )?;
 
     writeln!(
         handle,
         "Token Description: {}",
-        string_or_not_available(offchain_data.description)
+        report.description.as_deref().unwrap_or(UNAVAILABLE)
     )?;
     writeln!(
         handle,
         "Token Image: {}",
-        string_or_not_available(offchain_data.image)
+        report.image.as_deref().unwrap_or(UNAVAILABLE)
     )?;
 
     writeln!(
         handle,
         "Token Website: {}",
-        string_or_not_available(offchain_data.website)
+        report.website.as_deref().unwrap_or(UNAVAILABLE)
     )?;
 
     writeln!(
         handle,
         "Number of DNS records: {}",
-        string_or_not_available(dns_records)
+        report.dns_record_count.as_deref().unwrap_or(UNAVAILABLE)
+    )?;
+
+    writeln!(handle, "Compressed Asset: {}", report.is_compressed)?;
+
+    if report.creators.is_empty() {
+        writeln!(handle, "Creators: {}", UNAVAILABLE)?;
+    } else {
+        for creator in &report.creators {
+            writeln!(
+                handle,
+                "Creator: {} (verified: {}, share: {}%)",
+                creator.address, creator.verified, creator.share
+            )?;
+        }
+    }
+
+    match &report.collection {
+        Some(collection) => writeln!(
+            handle,
+            "Collection: {} (verified: {}, name: {})",
+            collection.mint,
+            collection.verified,
+            collection.name.as_deref().unwrap_or(UNAVAILABLE)
+        )?,
+        None => writeln!(handle, "Collection: {}", UNAVAILABLE)?,
+    }
+
+    match &report.holder_distribution {
+        Some(holders) => {
+            writeln!(handle, "Top 1 Holder: {:.2}%", holders.top1_pct)?;
+            writeln!(handle, "Top 10 Holders: {:.2}%", holders.top10_pct)?;
+            writeln!(handle, "Holder Concentration (HHI): {:.4}", holders.hhi)?;
+        }
+        None => {
+            writeln!(handle, "Top 1 Holder: {}", UNAVAILABLE)?;
+            writeln!(handle, "Top 10 Holders: {}", UNAVAILABLE)?;
+            writeln!(handle, "Holder Concentration (HHI): {}", UNAVAILABLE)?;
+        }
+    }
+
+    match &report.wrapped_asset {
+        WrappedAssetStatus::Wrapped(wrapped) => writeln!(
+            handle,
+            "Wrapped asset (origin chain: {}, origin address: {})",
+            wrapped.origin_chain, wrapped.origin_address
+        )?,
+        WrappedAssetStatus::Native => writeln!(handle, "Wrapped asset: native/unwrapped")?,
+        WrappedAssetStatus::Unavailable => {
+            writeln!(handle, "Wrapped asset: {}", UNAVAILABLE)?
+        }
+    }
+
+    Ok(())
+}
+
+fn print_json_report(report: &TokenReport) -> Result<()> {
+    let stdout = io::stdout();
+    let mut handle = io::BufWriter::new(stdout);
+
+    let json = serde_json::to_string(report).with_context(|| "Failed to serialize token report")?;
+    writeln!(handle, "{json}")?;
+
+    Ok(())
+}
+
+async fn batch(
+    client: &RpcClient,
+    file: Option<PathBuf>,
+    concurrency: usize,
+    config: &Config,
+) -> Result<()> {
+    let addresses = match file {
+        Some(path) => {
+            let reader = BufReader::new(
+                File::open(&path)
+                    .with_context(|| format!("Failed to open mint list at {}", path.display()))?,
+            );
+            read_mint_addresses(reader)?
+        }
+        None => read_mint_addresses(io::stdin().lock())?,
+    };
+
+    let bar = ProgressBar::new(addresses.len() as u64);
+    bar.set_message("Resolving mints...");
+
+    let mut succeeded = Vec::new();
+    let mut failed = Vec::new();
+
+    let mut results = stream::iter(addresses)
+        .map(|address| async move {
+            let result = resolve_mint(client, &address, config).await;
+            (address, result)
+        })
+        .buffer_unordered(concurrency);
+
+    while let Some((address, result)) = results.next().await {
+        bar.inc(1);
+        match result {
+            Ok((name, symbol, supply, decimals)) => {
+                let supply = supply
+                    .map(|supply| supply.to_string())
+                    .unwrap_or_else(|| UNAVAILABLE.to_string());
+                let decimals = decimals
+                    .map(|decimals| decimals.to_string())
+                    .unwrap_or_else(|| UNAVAILABLE.to_string());
+                succeeded.push(format!(
+                    "{address}: {name} ({symbol}) supply={supply} decimals={decimals}"
+                ))
+            }
+            Err(err) => failed.push(format!("{address}: {err:#}")),
+        }
+    }
+
+    bar.finish();
+
+    let stdout = io::stdout();
+    let mut handle = io::BufWriter::new(stdout);
+
+    for line in &succeeded {
+        writeln!(handle, "{line}")?;
+    }
+
+    writeln!(
+        handle,
+        "Resolved {} of {} mints ({} failed)",
+        succeeded.len(),
+        succeeded.len() + failed.len(),
+        failed.len()
     )?;
 
+    for failure in &failed {
+        writeln!(handle, "  FAILED {failure}")?;
+    }
+
     Ok(())
 }
 
+// Resolves a single mint's name/symbol/supply/decimals, failing independently
+// of the rest of the batch so one bad address doesn't abort the whole run.
+async fn resolve_mint(
+    client: &RpcClient,
+    address: &str,
+    config: &Config,
+) -> Result<(String, String, Option<u64>, Option<u8>)> {
+    let mint_pubkey = Pubkey::from_str(address)
+        .with_context(|| format!("'{address}' is not a valid mint address"))?;
+
+    let (metadata_res, mintdata_res) = tokio::join!(
+        fetch_token_metadata(client, &mint_pubkey, config),
+        fetch_token_mintdata(client, &mint_pubkey),
+    );
+
+    let metadata = metadata_res?;
+
+    // Compressed (Bubblegum) NFTs have no per-mint SPL Mint account at the
+    // asset id, so a missing mint account is expected there rather than an error.
+    let mintdata = match mintdata_res {
+        Ok(mintdata) => Some(mintdata),
+        Err(_) if metadata.is_compressed => None,
+        Err(err) => return Err(err),
+    };
+
+    Ok((
+        metadata.name,
+        metadata.symbol,
+        mintdata.as_ref().map(|mintdata| mintdata.supply),
+        mintdata.as_ref().map(|mintdata| mintdata.decimals),
+    ))
+}
+
 /*
 EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
 9KgvborfMPc1nzhXe9N8Q9pKTt57YdBWt9VqHnibdqjC
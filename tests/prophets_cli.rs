@@ -1,6 +1,7 @@
 use anyhow::Result;
 use assert_cmd::prelude::*; // Add methods on commands
 use predicates::prelude::*; // Used for writing assertions
+use std::fs;
 use std::process::Command; // Run programs
 
 #[test]
@@ -8,7 +9,7 @@ fn invalid_mint_address() -> Result<()> {
     let mut cmd = Command::cargo_bin("prophetbots-cli")?;
 
     // Invalid mint account address
-    cmd.arg("BJE5MMbqXjVwjAF7oxwPYXnTXDyspzZyt4vwenNw5ruG");
+    cmd.args(["inspect", "BJE5MMbqXjVwjAF7oxwPYXnTXDyspzZyt4vwenNw5ruG"]);
     cmd.assert().failure().stderr(predicate::str::contains(
         "it\'s likely because the token address is invalid",
     ));
@@ -21,7 +22,7 @@ fn invalid_solana_address() -> Result<()> {
     let mut cmd = Command::cargo_bin("prophetbots-cli")?;
 
     // Invalid solana account address
-    cmd.arg("asdf");
+    cmd.args(["inspect", "asdf"]);
     cmd.assert()
         .failure()
         .stderr(predicate::str::contains("invalid value"));
@@ -34,7 +35,7 @@ fn output_ft_details() -> Result<()> {
     let mut cmd = Command::cargo_bin("prophetbots-cli")?;
 
     // USDC mint account address
-    cmd.arg("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+    cmd.args(["inspect", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains("Token Name: USD Coin"))
@@ -49,12 +50,33 @@ fn output_ft_details() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn output_ft_details_json() -> Result<()> {
+    let mut cmd = Command::cargo_bin("prophetbots-cli")?;
+
+    // USDC mint account address
+    cmd.args([
+        "inspect",
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+        "--format",
+        "json",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\":\"USD Coin\""))
+        .stdout(predicate::str::contains("\"symbol\":\"USDC\""))
+        .stdout(predicate::str::contains("\"decimals\":6"))
+        .stdout(predicate::str::contains("\"description\":null"));
+
+    Ok(())
+}
+
 #[test]
 fn output_nft_details() -> Result<()> {
     let mut cmd = Command::cargo_bin("prophetbots-cli")?;
 
     // NFT mint account address
-    cmd.arg("9KgvborfMPc1nzhXe9N8Q9pKTt57YdBWt9VqHnibdqjC");
+    cmd.args(["inspect", "9KgvborfMPc1nzhXe9N8Q9pKTt57YdBWt9VqHnibdqjC"]);
     cmd.assert()
         .success()
         .stdout(predicate::str::contains(
@@ -70,3 +92,31 @@ fn output_nft_details() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn batch_resolves_mixed_valid_and_invalid_mints() -> Result<()> {
+    let mint_list_path = std::env::temp_dir().join("prophets_cli_batch_test_mints.txt");
+    fs::write(
+        &mint_list_path,
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v\nBJE5MMbqXjVwjAF7oxwPYXnTXDyspzZyt4vwenNw5ruG\n",
+    )?;
+
+    let mut cmd = Command::cargo_bin("prophetbots-cli")?;
+    cmd.args([
+        "batch",
+        "--file",
+        mint_list_path.to_str().unwrap(),
+        "--concurrency",
+        "2",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("USD Coin (USDC)"))
+        .stdout(predicate::str::contains(
+            "Resolved 1 of 2 mints (1 failed)",
+        ));
+
+    fs::remove_file(&mint_list_path)?;
+
+    Ok(())
+}